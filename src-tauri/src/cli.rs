@@ -0,0 +1,83 @@
+// Headless CLI entry path - lets the binary capture without opening the UI,
+// useful for scripting and CI doc generation
+//
+//   beautifullshot --screenshot-to out.png --region 0,0,800,600
+//   beautifullshot --screenshot-to out.png --monitor 1
+//   beautifullshot --screenshot-to out.png --window 4242
+//   beautifullshot --window-size 1280x800
+
+use crate::screenshot;
+
+struct ScreenshotArgs {
+    output_path: String,
+    monitor_id: Option<u32>,
+    region: Option<(i32, i32, u32, u32)>,
+    window_id: Option<u32>,
+}
+
+/// If the process was invoked with `--screenshot-to`, perform the capture and
+/// return the process exit code. Returns `None` when the app should launch normally.
+pub fn try_run_screenshot(args: &[String]) -> Option<i32> {
+    let output_path = find_value(args, "--screenshot-to")?;
+
+    let screenshot_args = ScreenshotArgs {
+        output_path,
+        monitor_id: find_value(args, "--monitor").and_then(|v| v.parse().ok()),
+        region: find_value(args, "--region").and_then(|v| parse_region(&v)),
+        window_id: find_value(args, "--window").and_then(|v| v.parse().ok()),
+    };
+
+    Some(match run_screenshot(screenshot_args) {
+        Ok(path) => {
+            println!("{}", path);
+            0
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            1
+        }
+    })
+}
+
+fn run_screenshot(args: ScreenshotArgs) -> Result<String, String> {
+    let png_bytes = if let Some(window_id) = args.window_id {
+        screenshot::capture_window(window_id, false, 1.0)?
+    } else if let Some((x, y, width, height)) = args.region {
+        screenshot::capture_region(x, y, width, height, false, 1.0)?
+    } else if let Some(monitor_id) = args.monitor_id {
+        screenshot::capture_monitor(monitor_id)?
+    } else {
+        screenshot::capture_fullscreen(false, 1.0)?
+    };
+
+    let path = crate::file_ops::resolve_and_validate_path(&args.output_path, png_bytes.len())?;
+    std::fs::write(&path, png_bytes).map_err(|e| format!("Failed to save file: {}", e))?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Parse `--window-size WxH` for the initial main window size on a normal launch
+pub fn parse_window_size(args: &[String]) -> Option<(u32, u32)> {
+    let value = find_value(args, "--window-size")?;
+    let (w, h) = value.split_once(['x', 'X'])?;
+    Some((w.trim().parse().ok()?, h.trim().parse().ok()?))
+}
+
+fn find_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .cloned()
+}
+
+fn parse_region(value: &str) -> Option<(i32, i32, u32, u32)> {
+    let parts: Vec<&str> = value.split(',').collect();
+    if parts.len() != 4 {
+        return None;
+    }
+    Some((
+        parts[0].trim().parse().ok()?,
+        parts[1].trim().parse().ok()?,
+        parts[2].trim().parse().ok()?,
+        parts[3].trim().parse().ok()?,
+    ))
+}
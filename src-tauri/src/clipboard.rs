@@ -2,15 +2,16 @@
 
 use arboard::{Clipboard, ImageData};
 use base64::{engine::general_purpose::STANDARD, Engine};
-use image::GenericImageView;
+use image::{GenericImageView, RgbaImage};
+
+use crate::screenshot::{encode_image, ImageFormat};
 
 // Maximum allowed image size: 50MB (prevents memory DoS attacks)
 const MAX_IMAGE_SIZE: usize = 50 * 1024 * 1024;
 
-/// Copy PNG image data to system clipboard
-/// Accepts base64-encoded PNG data (without data URL prefix)
-#[tauri::command]
-pub fn copy_image_to_clipboard(base64_data: &str) -> Result<(), String> {
+/// Decode base64-encoded PNG data to an RGBA image. Pure - does not touch the
+/// clipboard, so it's safe to call just to inspect or re-encode an image.
+fn decode_base64_image(base64_data: &str) -> Result<RgbaImage, String> {
     // Validate size before decoding (base64 is ~33% larger than binary)
     let estimated_size = base64_data.len() * 3 / 4;
     if estimated_size > MAX_IMAGE_SIZE {
@@ -38,17 +39,22 @@ pub fn copy_image_to_clipboard(base64_data: &str) -> Result<(), String> {
     let img = image::load_from_memory(&png_bytes)
         .map_err(|e| format!("Failed to load image: {}", e))?;
 
-    let (width, height) = img.dimensions();
-    let rgba = img.to_rgba8();
+    Ok(img.to_rgba8())
+}
+
+/// Copy PNG image data to system clipboard
+/// Accepts base64-encoded PNG data (without data URL prefix)
+#[tauri::command]
+pub fn copy_image_to_clipboard(base64_data: &str) -> Result<(), String> {
+    let rgba = decode_base64_image(base64_data)?;
+    let (width, height) = rgba.dimensions();
 
-    // Create clipboard image data
     let img_data = ImageData {
         width: width as usize,
         height: height as usize,
         bytes: rgba.into_raw().into(),
     };
 
-    // Copy to clipboard
     let mut clipboard =
         Clipboard::new().map_err(|e| format!("Failed to access clipboard: {}", e))?;
 
@@ -58,3 +64,13 @@ pub fn copy_image_to_clipboard(base64_data: &str) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Decode base64 PNG data and re-encode it in the requested format (lossy
+/// JPEG/WebP for chat uploads, or lossless PNG for editing), returning the
+/// encoded bytes for the frontend to hand to `save_file`. Doesn't touch the
+/// clipboard - pair with `copy_image_to_clipboard` for that.
+#[tauri::command]
+pub fn encode_image_as(base64_data: &str, format: ImageFormat) -> Result<Vec<u8>, String> {
+    let rgba = decode_base64_image(base64_data)?;
+    encode_image(&rgba, format)
+}
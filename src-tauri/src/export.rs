@@ -0,0 +1,137 @@
+// Animated image export - GIF (and optional WebP) output for short capture loops
+// Consumes the same same-dimension RGBA frame sequence the recording subsystem produces
+
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame, ImageEncoder, RgbaImage};
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of frames accepted in one export (bounds encode time and memory)
+const MAX_EXPORT_FRAMES: usize = 600;
+/// Maximum width or height accepted in one export (bounds output size)
+const MAX_EXPORT_DIMENSION: u32 = 4096;
+/// Maximum total raw RGBA bytes across all frames, analogous to `MAX_IMAGE_SIZE`/
+/// `MAX_FILE_SIZE` elsewhere - bounds the in-memory allocation regardless of how
+/// the frame count and dimensions individually combine
+const MAX_EXPORT_TOTAL_BYTES: usize = 200 * 1024 * 1024;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ExportFormat {
+    Gif,
+    WebP,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportResult {
+    pub bytes: Vec<u8>,
+    pub size: usize,
+}
+
+/// Export a sequence of same-dimension RGBA frames as an animated GIF, or a
+/// single frame as a still WebP
+#[tauri::command]
+pub fn export_gif(
+    frames: Vec<Vec<u8>>,
+    width: u32,
+    height: u32,
+    fps: u32,
+    loop_count: u16,
+    format: ExportFormat,
+) -> Result<ExportResult, String> {
+    if frames.is_empty() {
+        return Err("No frames to export".to_string());
+    }
+    if frames.len() > MAX_EXPORT_FRAMES {
+        return Err(format!(
+            "Too many frames ({}): max {} per export",
+            frames.len(),
+            MAX_EXPORT_FRAMES
+        ));
+    }
+    if fps == 0 {
+        return Err("fps must be greater than 0".to_string());
+    }
+    if width == 0 || height == 0 || width > MAX_EXPORT_DIMENSION || height > MAX_EXPORT_DIMENSION {
+        return Err(format!(
+            "Invalid dimensions {}x{}: max {m}x{m} per side",
+            width,
+            height,
+            m = MAX_EXPORT_DIMENSION
+        ));
+    }
+
+    let total_bytes = frames.len() * width as usize * height as usize * 4;
+    if total_bytes > MAX_EXPORT_TOTAL_BYTES {
+        return Err(format!(
+            "Export too large ({} MB): max {} MB total across all frames",
+            total_bytes / (1024 * 1024),
+            MAX_EXPORT_TOTAL_BYTES / (1024 * 1024)
+        ));
+    }
+
+    let images: Vec<RgbaImage> = frames
+        .into_iter()
+        .map(|raw| {
+            RgbaImage::from_raw(width, height, raw)
+                .ok_or_else(|| "Frame data does not match width/height".to_string())
+        })
+        .collect::<Result<_, _>>()?;
+
+    if matches!(format, ExportFormat::WebP) && images.len() > 1 {
+        return Err(
+            "Animated WebP export is not supported; use ExportFormat::Gif for multi-frame clips"
+                .to_string(),
+        );
+    }
+
+    let bytes = match format {
+        ExportFormat::Gif => encode_gif(&images, fps, loop_count)?,
+        ExportFormat::WebP => encode_webp(&images)?,
+    };
+
+    Ok(ExportResult {
+        size: bytes.len(),
+        bytes,
+    })
+}
+
+/// Writes a GIF with per-frame delay derived from `fps`. The `image`/`gif`
+/// crates quantize each frame to its own local palette; no palette is shared
+/// across frames
+fn encode_gif(frames: &[RgbaImage], fps: u32, loop_count: u16) -> Result<Vec<u8>, String> {
+    let delay_cs = (100 / fps).max(1);
+    let delay = Delay::from_numer_denom_ms(delay_cs * 10, 1);
+
+    let mut bytes: Vec<u8> = Vec::new();
+    {
+        let mut encoder = GifEncoder::new(&mut bytes);
+        encoder
+            .set_repeat(if loop_count == 0 {
+                Repeat::Infinite
+            } else {
+                Repeat::Finite(loop_count - 1)
+            })
+            .map_err(|e| e.to_string())?;
+
+        for image in frames {
+            let frame = Frame::from_parts(image.clone(), 0, 0, delay);
+            encoder.encode_frame(frame).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(bytes)
+}
+
+/// The `image` crate only supports single-frame (non-animated) WebP; callers
+/// requesting more than one frame are rejected before this is reached
+fn encode_webp(frames: &[RgbaImage]) -> Result<Vec<u8>, String> {
+    let first = frames.first().ok_or("No frames to export")?;
+    let mut bytes: Vec<u8> = Vec::new();
+    image::codecs::webp::WebPEncoder::new_lossless(&mut bytes)
+        .write_image(
+            first.as_raw(),
+            first.width(),
+            first.height(),
+            image::ExtendedColorType::Rgba8,
+        )
+        .map_err(|e| e.to_string())?;
+    Ok(bytes)
+}
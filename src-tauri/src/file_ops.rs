@@ -5,20 +5,20 @@ use std::path::PathBuf;
 /// Maximum file size limit (50MB) - prevents DoS from excessively large exports
 const MAX_FILE_SIZE: usize = 50 * 1024 * 1024;
 
-/// Save binary data to file at specified path
-/// Security: Validates path and enforces size limits
-#[tauri::command]
-pub async fn save_file(path: String, data: Vec<u8>) -> Result<String, String> {
+/// Validate and canonicalize a save path, enforcing the size limit and
+/// rejecting directory traversal. Shared by the `save_file` command and the
+/// headless CLI screenshot path so both get the same security guarantees.
+pub(crate) fn resolve_and_validate_path(path: &str, data_len: usize) -> Result<PathBuf, String> {
     // Enforce file size limit
-    if data.len() > MAX_FILE_SIZE {
+    if data_len > MAX_FILE_SIZE {
         return Err(format!(
             "File size ({} MB) exceeds maximum allowed ({} MB)",
-            data.len() / (1024 * 1024),
+            data_len / (1024 * 1024),
             MAX_FILE_SIZE / (1024 * 1024)
         ));
     }
 
-    let path = PathBuf::from(&path);
+    let path = PathBuf::from(path);
 
     // Canonicalize path to prevent directory traversal attacks
     // For new files, canonicalize the parent directory
@@ -47,6 +47,15 @@ pub async fn save_file(path: String, data: Vec<u8>) -> Result<String, String> {
         return Err("Invalid path: directory traversal not allowed".to_string());
     }
 
+    Ok(canonical_path)
+}
+
+/// Save binary data to file at specified path
+/// Security: Validates path and enforces size limits
+#[tauri::command]
+pub async fn save_file(path: String, data: Vec<u8>) -> Result<String, String> {
+    let canonical_path = resolve_and_validate_path(&path, data.len())?;
+
     std::fs::write(&canonical_path, data)
         .map_err(|e| format!("Failed to save file: {}", e))?;
 
@@ -1,8 +1,18 @@
 // BeautyFullShot - Screenshot Beautification App
 // Tauri commands: https://tauri.app/develop/calling-rust/
 
+use tauri::Manager;
+
+mod cli;
+mod clipboard;
+mod export;
+mod file_ops;
+mod overlay;
 mod permissions;
+mod recording;
 mod screenshot;
+mod shortcuts;
+mod window_state;
 
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -11,19 +21,64 @@ fn greet(name: &str) -> String {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let args: Vec<String> = std::env::args().collect();
+
+    // Headless capture: `--screenshot-to <path>` captures and exits without
+    // ever opening the UI
+    if let Some(exit_code) = cli::try_run_screenshot(&args) {
+        std::process::exit(exit_code);
+    }
+
+    let window_size = cli::parse_window_size(&args);
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_notification::init())
+        .manage(recording::RecordingState::default())
+        .setup(move |app| {
+            shortcuts::register_shortcuts(app.handle())?;
+
+            if let Some(main_window) = app.get_webview_window("main") {
+                window_state::watch_window(app.handle(), &main_window);
+            }
+            window_state::restore_window_state(app.handle().clone())?;
+
+            if let Some((width, height)) = window_size {
+                if let Some(main_window) = app.get_webview_window("main") {
+                    let _ = main_window.set_size(tauri::PhysicalSize::new(width, height));
+                }
+            }
+
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             greet,
             screenshot::capture_fullscreen,
             screenshot::capture_region,
+            screenshot::capture_monitor,
+            screenshot::capture_all_monitors,
             screenshot::capture_window,
             screenshot::get_windows,
             screenshot::get_monitors,
             permissions::check_screen_permission,
             permissions::check_wayland,
+            recording::start_recording,
+            recording::stop_recording,
+            export::export_gif,
+            overlay::get_screenshot_data,
+            overlay::clear_screenshot_data,
+            overlay::capture_and_show_overlay,
+            overlay::hide_overlay_window,
+            overlay::create_overlay_window,
+            overlay::close_overlay_window,
+            window_state::save_window_state,
+            window_state::restore_window_state,
+            file_ops::save_file,
+            file_ops::get_pictures_dir,
+            file_ops::get_desktop_dir,
+            clipboard::copy_image_to_clipboard,
+            clipboard::encode_image_as,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
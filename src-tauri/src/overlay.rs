@@ -30,7 +30,7 @@ pub fn clear_screenshot_data() {
 #[tauri::command]
 pub async fn capture_and_show_overlay(app: AppHandle) -> Result<(), String> {
     // Capture screenshot using same function as fullscreen
-    let screenshot_base64 = crate::screenshot::capture_fullscreen()?;
+    let screenshot_base64 = crate::screenshot::capture_fullscreen(false, 1.0)?;
 
     // Store screenshot
     {
@@ -47,7 +47,7 @@ pub async fn capture_and_show_overlay(app: AppHandle) -> Result<(), String> {
             w
         }
         None => {
-            WebviewWindowBuilder::new(
+            let window = WebviewWindowBuilder::new(
                 &app,
                 "region-overlay",
                 WebviewUrl::App("overlay.html".into()),
@@ -68,7 +68,10 @@ pub async fn capture_and_show_overlay(app: AppHandle) -> Result<(), String> {
                     .unwrap_or_else(|poisoned| poisoned.into_inner());
                 *data = None;
                 format!("{}", e)
-            })?
+            })?;
+
+            crate::window_state::watch_window(&app, &window);
+            window
         }
     };
 
@@ -0,0 +1,279 @@
+// Screen recording module - captures a region over time and encodes to MP4
+// Frames are grabbed from xcap at the requested fps and piped into ffmpeg
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use image::RgbaImage;
+use tauri::{Emitter, Manager};
+use xcap::Monitor;
+
+/// Hard ceiling on frames held in RAM before a recording is force-stopped
+/// (prevents unbounded memory growth from a forgotten recording)
+const MAX_TOTAL_FRAMES: usize = 18_000; // ~10 minutes at 30fps
+
+/// Shared recording flag and frame buffer, stored as Tauri managed state
+#[derive(Default)]
+pub struct RecordingState {
+    recording: Arc<Mutex<bool>>,
+    frames: Arc<Mutex<Vec<RgbaImage>>>,
+    fps: Arc<Mutex<u32>>,
+}
+
+/// Encode a sequence of same-dimension RGBA frames to an H.264 MP4 via ffmpeg
+fn encode_frames_to_mp4(frames: &[RgbaImage], fps: u32, output_path: &str) -> Result<(), String> {
+    let (width, height) = frames
+        .first()
+        .map(|f| (f.width(), f.height()))
+        .ok_or("No frames captured")?;
+
+    let mut child = Command::new("ffmpeg")
+        .args([
+            "-y",
+            "-f",
+            "rawvideo",
+            "-pixel_format",
+            "rgba",
+            "-video_size",
+            &format!("{}x{}", width, height),
+            "-framerate",
+            &fps.to_string(),
+            "-i",
+            "-",
+            "-c:v",
+            "libx264",
+            "-pix_fmt",
+            "yuv420p",
+            output_path,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to start ffmpeg: {}", e))?;
+
+    {
+        let stdin = child
+            .stdin
+            .as_mut()
+            .ok_or("Failed to open ffmpeg stdin")?;
+        for frame in frames {
+            stdin
+                .write_all(frame.as_raw())
+                .map_err(|e| format!("Failed to write frame to ffmpeg: {}", e))?;
+        }
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| format!("ffmpeg process failed: {}", e))?;
+
+    if !status.success() {
+        return Err(format!("ffmpeg exited with status {}", status));
+    }
+
+    Ok(())
+}
+
+/// Start recording a region of a monitor at the given frame rate
+#[tauri::command]
+pub async fn start_recording(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, RecordingState>,
+    monitor_id: u32,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    fps: u32,
+) -> Result<(), String> {
+    if fps == 0 || fps > 60 {
+        return Err("fps must be between 1 and 60".to_string());
+    }
+
+    {
+        let mut recording = state
+            .recording
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if *recording {
+            return Err("Recording already in progress".to_string());
+        }
+        *recording = true;
+    }
+
+    {
+        let mut frames = state
+            .frames
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        frames.clear();
+    }
+
+    {
+        let mut stored_fps = state
+            .fps
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *stored_fps = fps;
+    }
+
+    let recording = state.recording.clone();
+    let frames = state.frames.clone();
+    let app = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let frame_interval_us = 1_000_000u64 / fps as u64;
+        let start = Instant::now();
+        let mut frame_index: u64 = 0;
+
+        loop {
+            {
+                let active = recording
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                if !*active {
+                    break;
+                }
+            }
+
+            let monitor = Monitor::all()
+                .ok()
+                .and_then(|monitors| monitors.into_iter().find(|m| m.id().unwrap_or(0) == monitor_id));
+
+            let Some(monitor) = monitor else {
+                break;
+            };
+
+            if let Ok(image) = monitor.capture_image() {
+                let img_width = image.width();
+                let img_height = image.height();
+                let start_x = x.max(0) as u32;
+                let start_y = y.max(0) as u32;
+                let crop_width = width.min(img_width.saturating_sub(start_x));
+                let crop_height = height.min(img_height.saturating_sub(start_y));
+
+                if crop_width > 0 && crop_height > 0 {
+                    let cropped =
+                        image::imageops::crop_imm(&image, start_x, start_y, crop_width, crop_height)
+                            .to_image();
+
+                    let mut buf = frames
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner());
+                    if buf.len() >= MAX_TOTAL_FRAMES {
+                        drop(buf);
+                        // Stop capturing new frames, but leave `recording` true so
+                        // the buffer already captured isn't orphaned - the frontend
+                        // (listening for this event) must still call
+                        // `stop_recording` to drain and encode it.
+                        let _ = app.emit("recording-capped", ());
+                        break;
+                    }
+                    buf.push(cropped);
+                }
+            }
+
+            frame_index += 1;
+            let target = Duration::from_micros(frame_index * frame_interval_us);
+            let elapsed = start.elapsed();
+            if target > elapsed {
+                tokio::time::sleep(target - elapsed).await;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Stop the in-progress recording and encode the captured frames to `output_path`
+#[tauri::command]
+pub async fn stop_recording(
+    state: tauri::State<'_, RecordingState>,
+    output_path: String,
+) -> Result<String, String> {
+    {
+        let mut recording = state
+            .recording
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !*recording {
+            return Err("No recording in progress".to_string());
+        }
+        *recording = false;
+    }
+
+    // Give the capture loop a moment to observe the flag and exit its last sleep
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let frames = {
+        let mut buf = state
+            .frames
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        std::mem::take(&mut *buf)
+    };
+
+    if frames.is_empty() {
+        return Err("No frames captured".to_string());
+    }
+
+    let fps = *state
+        .fps
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    encode_frames_to_mp4(&frames, fps, &output_path)?;
+
+    Ok(output_path)
+}
+
+/// Toggle recording from the global shortcut: starts a full-primary-monitor
+/// recording if idle, or stops and saves the in-progress one
+pub fn toggle_recording(app: tauri::AppHandle) {
+    let app_handle = app.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let state = app_handle.state::<RecordingState>();
+        let is_recording = *state
+            .recording
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if is_recording {
+            let output_path = default_recording_path();
+            // stop_recording clears the flag before encoding, so recording has
+            // ended whether or not the encode succeeded
+            let _ = stop_recording(state, output_path).await;
+            let _ = app_handle.emit("recording-state", false);
+        } else {
+            let monitors = match Monitor::all() {
+                Ok(m) => m,
+                Err(_) => return,
+            };
+            let Some(primary) = monitors.into_iter().find(|m| m.is_primary().unwrap_or(false)) else {
+                return;
+            };
+            let (monitor_id, width, height) = (
+                primary.id().unwrap_or(0),
+                primary.width().unwrap_or(0),
+                primary.height().unwrap_or(0),
+            );
+
+            let result =
+                start_recording(app_handle.clone(), state, monitor_id, 0, 0, width, height, 30).await;
+            let _ = app_handle.emit("recording-state", result.is_ok());
+        }
+    });
+}
+
+/// Default output path for shortcut-triggered recordings
+fn default_recording_path() -> String {
+    let dir = dirs::picture_dir()
+        .map(|p| p.join("BeautyShot"))
+        .unwrap_or_else(std::env::temp_dir);
+    let _ = std::fs::create_dir_all(&dir);
+    dir.join("recording.mp4").to_string_lossy().to_string()
+}
@@ -1,10 +1,14 @@
 // Screenshot capture module using xcap crate
 // Provides fullscreen, region, and window capture functionality
 
-use image::ImageEncoder;
+use image::{ImageEncoder, RgbaImage};
+use mouse_position::mouse_position::Mouse;
 use serde::{Deserialize, Serialize};
 use xcap::{Monitor, Window as XcapWindow};
 
+/// Maximum image size (50MB, measured as raw RGBA bytes) allowed post-scale
+const MAX_CAPTURE_IMAGE_SIZE: usize = 50 * 1024 * 1024;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MonitorInfo {
     pub id: u32,
@@ -27,37 +31,158 @@ pub struct WindowInfo {
     pub height: u32,
 }
 
-/// Convert RgbaImage to PNG bytes
-fn image_to_png_bytes(img: &image::RgbaImage) -> Result<Vec<u8>, String> {
-    let mut bytes: Vec<u8> = Vec::new();
-    let encoder = image::codecs::png::PngEncoder::new(&mut bytes);
-    encoder
-        .write_image(
-            img.as_raw(),
-            img.width(),
-            img.height(),
-            image::ExtendedColorType::Rgba8,
+/// Output format for an encoded capture
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ImageFormat {
+    Png,
+    Jpeg { quality: u8 },
+    WebP { quality: u8 },
+}
+
+fn validate_quality(quality: u8) -> Result<(), String> {
+    if !(1..=100).contains(&quality) {
+        return Err("quality must be between 1 and 100".to_string());
+    }
+    Ok(())
+}
+
+/// Encode an RgbaImage in the requested format
+pub(crate) fn encode_image(img: &RgbaImage, format: ImageFormat) -> Result<Vec<u8>, String> {
+    match format {
+        ImageFormat::Png => {
+            let mut bytes: Vec<u8> = Vec::new();
+            let encoder = image::codecs::png::PngEncoder::new(&mut bytes);
+            encoder
+                .write_image(
+                    img.as_raw(),
+                    img.width(),
+                    img.height(),
+                    image::ExtendedColorType::Rgba8,
+                )
+                .map_err(|e| e.to_string())?;
+            Ok(bytes)
+        }
+        ImageFormat::Jpeg { quality } => {
+            validate_quality(quality)?;
+            // JPEG has no alpha channel
+            let rgb = image::DynamicImage::ImageRgba8(img.clone()).to_rgb8();
+            let mut bytes: Vec<u8> = Vec::new();
+            image::codecs::jpeg::JpegEncoder::new_with_quality(&mut bytes, quality)
+                .write_image(
+                    rgb.as_raw(),
+                    rgb.width(),
+                    rgb.height(),
+                    image::ExtendedColorType::Rgb8,
+                )
+                .map_err(|e| e.to_string())?;
+            Ok(bytes)
+        }
+        ImageFormat::WebP { quality } => {
+            validate_quality(quality)?;
+            let encoder = webp::Encoder::from_rgba(img.as_raw(), img.width(), img.height());
+            Ok(encoder.encode(quality as f32).to_vec())
+        }
+    }
+}
+
+/// Composite a generic arrow-pointer glyph onto `image` at the current cursor
+/// position. xcap doesn't expose the OS's actual cursor bitmap, so this draws
+/// a synthetic marker rather than the real system pointer.
+fn composite_cursor(image: &mut RgbaImage, origin_x: i32, origin_y: i32) {
+    let Mouse::Position { x: cursor_x, y: cursor_y } = Mouse::get_mouse_position() else {
+        return;
+    };
+
+    let glyph = cursor_glyph();
+    let x = (cursor_x - origin_x) as i64;
+    let y = (cursor_y - origin_y) as i64;
+    image::imageops::overlay(image, &glyph, x, y);
+}
+
+/// A small synthetic arrow glyph used to mark the cursor location
+fn cursor_glyph() -> RgbaImage {
+    let mut glyph = RgbaImage::new(12, 18);
+    for y in 0..18u32 {
+        let row_width = (y + 1).min(10);
+        for x in 0..row_width {
+            glyph.put_pixel(x, y, image::Rgba([0, 0, 0, 255]));
+        }
+    }
+    glyph
+}
+
+/// Resize the image by `scale` (Lanczos3) and enforce the post-scale size limit
+fn apply_scale(image: RgbaImage, scale: f32) -> Result<RgbaImage, String> {
+    let image = if scale == 1.0 {
+        image
+    } else {
+        if scale <= 0.0 {
+            return Err("scale must be greater than 0".to_string());
+        }
+        let new_width = ((image.width() as f32) * scale).round().max(1.0) as u32;
+        let new_height = ((image.height() as f32) * scale).round().max(1.0) as u32;
+
+        // Check the post-scale size *before* allocating the resized buffer,
+        // so an oversized `scale` fails cleanly instead of attempting a huge
+        // allocation first.
+        let byte_size = new_width as usize * new_height as usize * 4;
+        if byte_size > MAX_CAPTURE_IMAGE_SIZE {
+            return Err(format!(
+                "Image size ({} MB) exceeds maximum allowed ({} MB)",
+                byte_size / (1024 * 1024),
+                MAX_CAPTURE_IMAGE_SIZE / (1024 * 1024)
+            ));
+        }
+
+        image::imageops::resize(
+            &image,
+            new_width,
+            new_height,
+            image::imageops::FilterType::Lanczos3,
         )
-        .map_err(|e| e.to_string())?;
-    Ok(bytes)
+    };
+
+    let byte_size = image.width() as usize * image.height() as usize * 4;
+    if byte_size > MAX_CAPTURE_IMAGE_SIZE {
+        return Err(format!(
+            "Image size ({} MB) exceeds maximum allowed ({} MB)",
+            byte_size / (1024 * 1024),
+            MAX_CAPTURE_IMAGE_SIZE / (1024 * 1024)
+        ));
+    }
+
+    Ok(image)
 }
 
 /// Capture primary monitor
 #[tauri::command]
-pub fn capture_fullscreen() -> Result<Vec<u8>, String> {
+pub fn capture_fullscreen(include_cursor: bool, scale: f32) -> Result<Vec<u8>, String> {
     let monitors = Monitor::all().map_err(|e| e.to_string())?;
     let primary = monitors
         .into_iter()
         .find(|m| m.is_primary().unwrap_or(false))
         .ok_or("No primary monitor found")?;
 
-    let image = primary.capture_image().map_err(|e| e.to_string())?;
-    image_to_png_bytes(&image)
+    let mut image = primary.capture_image().map_err(|e| e.to_string())?;
+    if include_cursor {
+        composite_cursor(&mut image, primary.x().unwrap_or(0), primary.y().unwrap_or(0));
+    }
+    let image = apply_scale(image, scale)?;
+
+    encode_image(&image, ImageFormat::Png)
 }
 
 /// Capture specific region from primary monitor
 #[tauri::command]
-pub fn capture_region(x: i32, y: i32, width: u32, height: u32) -> Result<Vec<u8>, String> {
+pub fn capture_region(
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    include_cursor: bool,
+    scale: f32,
+) -> Result<Vec<u8>, String> {
     let monitors = Monitor::all().map_err(|e| e.to_string())?;
     let monitor = monitors
         .into_iter()
@@ -79,9 +204,66 @@ pub fn capture_region(x: i32, y: i32, width: u32, height: u32) -> Result<Vec<u8>
     }
 
     // Crop to region
-    let cropped = image::imageops::crop_imm(&image, start_x, start_y, crop_width, crop_height).to_image();
+    let mut cropped =
+        image::imageops::crop_imm(&image, start_x, start_y, crop_width, crop_height).to_image();
+
+    if include_cursor {
+        let origin_x = monitor.x().unwrap_or(0) + start_x as i32;
+        let origin_y = monitor.y().unwrap_or(0) + start_y as i32;
+        composite_cursor(&mut cropped, origin_x, origin_y);
+    }
+    let cropped = apply_scale(cropped, scale)?;
+
+    encode_image(&cropped, ImageFormat::Png)
+}
 
-    image_to_png_bytes(&cropped)
+/// Capture a specific monitor by id
+#[tauri::command]
+pub fn capture_monitor(monitor_id: u32) -> Result<Vec<u8>, String> {
+    let monitors = Monitor::all().map_err(|e| e.to_string())?;
+    let monitor = monitors
+        .into_iter()
+        .find(|m| m.id().unwrap_or(0) == monitor_id)
+        .ok_or("Monitor not found")?;
+
+    let image = monitor.capture_image().map_err(|e| e.to_string())?;
+    encode_image(&image, ImageFormat::Png)
+}
+
+/// Capture every monitor and composite them into one image spanning the
+/// full virtual desktop, positioned using each monitor's reported origin
+#[tauri::command]
+pub fn capture_all_monitors() -> Result<Vec<u8>, String> {
+    let monitors = Monitor::all().map_err(|e| e.to_string())?;
+    if monitors.is_empty() {
+        return Err("No monitors found".to_string());
+    }
+
+    let min_x = monitors.iter().map(|m| m.x().unwrap_or(0)).min().unwrap();
+    let min_y = monitors.iter().map(|m| m.y().unwrap_or(0)).min().unwrap();
+    let max_x = monitors
+        .iter()
+        .map(|m| m.x().unwrap_or(0) + m.width().unwrap_or(0) as i32)
+        .max()
+        .unwrap();
+    let max_y = monitors
+        .iter()
+        .map(|m| m.y().unwrap_or(0) + m.height().unwrap_or(0) as i32)
+        .max()
+        .unwrap();
+
+    let canvas_width = (max_x - min_x).max(0) as u32;
+    let canvas_height = (max_y - min_y).max(0) as u32;
+    let mut canvas = image::RgbaImage::new(canvas_width, canvas_height);
+
+    for monitor in &monitors {
+        let image = monitor.capture_image().map_err(|e| e.to_string())?;
+        let offset_x = monitor.x().unwrap_or(0) - min_x;
+        let offset_y = monitor.y().unwrap_or(0) - min_y;
+        image::imageops::overlay(&mut canvas, &image, offset_x as i64, offset_y as i64);
+    }
+
+    encode_image(&canvas, ImageFormat::Png)
 }
 
 /// Get list of capturable windows
@@ -110,15 +292,20 @@ pub fn get_windows() -> Result<Vec<WindowInfo>, String> {
 
 /// Capture specific window by ID
 #[tauri::command]
-pub fn capture_window(window_id: u32) -> Result<Vec<u8>, String> {
+pub fn capture_window(window_id: u32, include_cursor: bool, scale: f32) -> Result<Vec<u8>, String> {
     let windows = XcapWindow::all().map_err(|e| e.to_string())?;
     let window = windows
         .into_iter()
         .find(|w| w.id().unwrap_or(0) == window_id)
         .ok_or("Window not found")?;
 
-    let image = window.capture_image().map_err(|e| e.to_string())?;
-    image_to_png_bytes(&image)
+    let mut image = window.capture_image().map_err(|e| e.to_string())?;
+    if include_cursor {
+        composite_cursor(&mut image, window.x().unwrap_or(0), window.y().unwrap_or(0));
+    }
+    let image = apply_scale(image, scale)?;
+
+    encode_image(&image, ImageFormat::Png)
 }
 
 /// Get monitor list
@@ -20,5 +20,17 @@ pub fn register_shortcuts(app: &tauri::AppHandle) -> Result<(), Box<dyn std::err
         },
     )?;
 
+    // Super (Cmd on macOS) + Shift + R toggles screen recording start/stop
+    let recording_shortcut = Shortcut::new(Some(Modifiers::SUPER | Modifiers::SHIFT), Code::KeyR);
+
+    app.global_shortcut().on_shortcut(
+        recording_shortcut,
+        |app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                crate::recording::toggle_recording(app.clone());
+            }
+        },
+    )?;
+
     Ok(())
 }
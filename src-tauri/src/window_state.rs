@@ -0,0 +1,143 @@
+// Window geometry persistence - remembers each window's position/size
+// across sessions, restoring it on startup
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, WebviewWindow, WindowEvent};
+
+use crate::screenshot::MonitorInfo;
+
+const STATE_FILE_NAME: &str = "window-state.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WindowGeometry {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    maximized: bool,
+    fullscreen: bool,
+}
+
+type WindowStateMap = HashMap<String, WindowGeometry>;
+
+fn state_file_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(STATE_FILE_NAME))
+}
+
+fn load_state(app: &AppHandle) -> WindowStateMap {
+    state_file_path(app)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn write_state(app: &AppHandle, state: &WindowStateMap) -> Result<(), String> {
+    let path = state_file_path(app)?;
+    let json = serde_json::to_string_pretty(state).map_err(|e| e.to_string())?;
+    std::fs::write(path, json).map_err(|e| e.to_string())
+}
+
+/// Clamp a saved rectangle so it can't be restored fully off-screen, using
+/// the bounding box of every currently connected monitor
+fn clamp_to_monitors(geometry: &mut WindowGeometry, monitors: &[MonitorInfo]) {
+    if monitors.is_empty() {
+        return;
+    }
+
+    let min_x = monitors.iter().map(|m| m.x).min().unwrap();
+    let min_y = monitors.iter().map(|m| m.y).min().unwrap();
+    let max_x = monitors
+        .iter()
+        .map(|m| m.x + m.width as i32)
+        .max()
+        .unwrap();
+    let max_y = monitors
+        .iter()
+        .map(|m| m.y + m.height as i32)
+        .max()
+        .unwrap();
+
+    // A monitor query can report width/height as 0 (e.g. `unwrap_or(0)` in
+    // `get_monitors`), collapsing the bounding box to zero width or height.
+    // Guard the upper clamp bound so it's never below the lower one.
+    geometry.x = geometry.x.clamp(min_x, max_x.max(min_x + 1) - 1);
+    geometry.y = geometry.y.clamp(min_y, max_y.max(min_y + 1) - 1);
+    geometry.width = geometry.width.min((max_x - min_x).max(1) as u32);
+    geometry.height = geometry.height.min((max_y - min_y).max(1) as u32);
+}
+
+fn capture_geometry(window: &WebviewWindow) -> Result<WindowGeometry, String> {
+    let position = window.outer_position().map_err(|e| e.to_string())?;
+    let size = window.outer_size().map_err(|e| e.to_string())?;
+    Ok(WindowGeometry {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        maximized: window.is_maximized().unwrap_or(false),
+        fullscreen: window.is_fullscreen().unwrap_or(false),
+    })
+}
+
+fn apply_geometry(window: &WebviewWindow, geometry: &WindowGeometry) {
+    let _ = window.set_position(PhysicalPosition::new(geometry.x, geometry.y));
+    let _ = window.set_size(PhysicalSize::new(geometry.width, geometry.height));
+    if geometry.maximized {
+        let _ = window.maximize();
+    }
+    if geometry.fullscreen {
+        let _ = window.set_fullscreen(true);
+    }
+}
+
+fn save_window(app: &AppHandle, window: &WebviewWindow) -> Result<(), String> {
+    let geometry = capture_geometry(window)?;
+    let mut state = load_state(app);
+    state.insert(window.label().to_string(), geometry);
+    write_state(app, &state)
+}
+
+/// Save every open window's geometry to disk
+#[tauri::command]
+pub fn save_window_state(app: AppHandle) -> Result<(), String> {
+    for window in app.webview_windows().values() {
+        save_window(&app, window)?;
+    }
+    Ok(())
+}
+
+/// Restore every open window's geometry from disk, clamped to the current monitors
+#[tauri::command]
+pub fn restore_window_state(app: AppHandle) -> Result<(), String> {
+    let state = load_state(&app);
+    let monitors = crate::screenshot::get_monitors().unwrap_or_default();
+
+    for (label, window) in app.webview_windows() {
+        if let Some(mut geometry) = state.get(&label).cloned() {
+            clamp_to_monitors(&mut geometry, &monitors);
+            apply_geometry(&window, &geometry);
+        }
+    }
+    Ok(())
+}
+
+/// Save a window's geometry automatically on move, resize, and close
+pub fn watch_window(app: &AppHandle, window: &WebviewWindow) {
+    let app = app.clone();
+    let label = window.label().to_string();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Moved(_) | WindowEvent::Resized(_) | WindowEvent::CloseRequested { .. } =
+            event
+        {
+            if let Some(window) = app.get_webview_window(&label) {
+                let _ = save_window(&app, &window);
+            }
+        }
+    });
+}